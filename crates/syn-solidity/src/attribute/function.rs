@@ -1,7 +1,7 @@
 use super::{kw, Modifier, Mutability, Override, SolPath, VariableAttribute, Visibility};
+use indexmap::IndexSet;
 use proc_macro2::Span;
 use std::{
-    collections::HashSet,
     fmt,
     hash::{Hash, Hasher},
     mem,
@@ -14,13 +14,16 @@ use syn::{
     Error, Ident, Result, Token,
 };
 
-/// A list of unique function attributes. Used in
+/// A list of unique function attributes, in source order. Used in
 /// [ItemFunction][crate::ItemFunction].
+///
+/// The order is preserved from parsing so that [ToTokens][syn::ToTokens]/[Display][fmt::Display]
+/// output is deterministic and round-trippable, while duplicate attributes are still rejected.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
-pub struct FunctionAttributes(pub HashSet<FunctionAttribute>);
+pub struct FunctionAttributes(pub IndexSet<FunctionAttribute>);
 
 impl Deref for FunctionAttributes {
-    type Target = HashSet<FunctionAttribute>;
+    type Target = IndexSet<FunctionAttribute>;
 
     fn deref(&self) -> &Self::Target {
         &self.0
@@ -35,7 +38,7 @@ impl DerefMut for FunctionAttributes {
 
 impl Parse for FunctionAttributes {
     fn parse(input: ParseStream<'_>) -> Result<Self> {
-        let mut attributes = HashSet::<FunctionAttribute>::new();
+        let mut attributes = IndexSet::<FunctionAttribute>::new();
         while !(input.is_empty()
             || input.peek(kw::returns)
             || input.peek(Token![;])
@@ -56,7 +59,7 @@ impl Parse for FunctionAttributes {
 impl FunctionAttributes {
     #[inline]
     pub fn new() -> Self {
-        Self(HashSet::new())
+        Self(IndexSet::new())
     }
 
     pub fn visibility(&self) -> Option<Visibility> {
@@ -307,3 +310,15 @@ impl FunctionAttribute {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn function_attributes_preserve_source_order() {
+        let attrs: FunctionAttributes = syn::parse_str("virtual public").unwrap();
+        let rendered: Vec<_> = attrs.iter().map(ToString::to_string).collect();
+        assert_eq!(rendered, vec!["virtual".to_string(), "public".to_string()]);
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{
+    FunctionAttribute, FunctionAttributes, Modifier, Override, Parameters, SolIdent, SolPath,
+    Type, TypeArray, TypeTuple, VariableDeclaration,
+};
+use std::mem;
+
+/// A trait for transforming syntax tree nodes by value.
+///
+/// This mirrors [`syn::fold`](https://docs.rs/syn/latest/syn/fold/index.html): each method takes
+/// ownership of a node and returns a (possibly rewritten) node of the same type. The default
+/// implementations simply recurse into the node's children, so implementors only need to
+/// override the methods for the nodes they actually want to rewrite.
+///
+/// This is the by-value counterpart to the borrowing [`Visit`][crate::Visit] and
+/// [`VisitMut`][crate::VisitMut] traversals: use `Fold` when you need to replace nodes outright,
+/// e.g. renaming every parameter identifier, stripping `virtual`/`override` attributes, or
+/// substituting a user type alias throughout a signature.
+pub trait Fold {
+    /// Folds a [`Type`], recursing into array element types, tuple members, and, via
+    /// [`fold_sol_path`][Self::fold_sol_path], `Custom` paths (e.g. a user type alias).
+    fn fold_type(&mut self, ty: Type) -> Type {
+        match ty {
+            Type::Array(array) => Type::Array(self.fold_type_array(array)),
+            Type::Tuple(tuple) => Type::Tuple(self.fold_type_tuple(tuple)),
+            Type::Custom(path) => Type::Custom(self.fold_sol_path(path)),
+            ty => ty,
+        }
+    }
+
+    /// Folds a [`TypeArray`]'s element type.
+    fn fold_type_array(&mut self, mut array: TypeArray) -> TypeArray {
+        array.ty = Box::new(self.fold_type(*array.ty));
+        array
+    }
+
+    /// Folds each member of a [`TypeTuple`].
+    fn fold_type_tuple(&mut self, mut tuple: TypeTuple) -> TypeTuple {
+        tuple.types = tuple.types.into_iter().map(|ty| self.fold_type(ty)).collect();
+        tuple
+    }
+
+    /// Folds a [`SolIdent`].
+    fn fold_ident(&mut self, ident: SolIdent) -> SolIdent {
+        ident
+    }
+
+    /// Folds a [`VariableDeclaration`].
+    fn fold_variable_declaration(&mut self, mut var: VariableDeclaration) -> VariableDeclaration {
+        var.name = var.name.map(|name| self.fold_ident(name));
+        var.ty = self.fold_type(var.ty);
+        var
+    }
+
+    /// Folds a list of [`VariableDeclaration`]s.
+    fn fold_parameters<P: Default>(&mut self, parameters: Parameters<P>) -> Parameters<P> {
+        parameters.into_iter().map(|var| self.fold_variable_declaration(var)).collect()
+    }
+
+    /// Folds a [`SolPath`].
+    fn fold_sol_path(&mut self, path: SolPath) -> SolPath {
+        path
+    }
+
+    /// Folds a [`Modifier`] invocation, including its argument expressions.
+    fn fold_modifier(&mut self, mut modifier: Modifier) -> Modifier {
+        modifier.name = self.fold_sol_path(modifier.name);
+        if let Some(arguments) = &mut modifier.arguments {
+            arguments.list = mem::take(&mut arguments.list)
+                .into_iter()
+                .map(|arg| self.fold_expr(arg))
+                .collect();
+        }
+        modifier
+    }
+
+    /// Folds an [`Override`] attribute's paths.
+    fn fold_override(&mut self, mut o: Override) -> Override {
+        o.paths = o.paths.into_iter().map(|path| self.fold_sol_path(path)).collect();
+        o
+    }
+
+    /// Folds a `syn` expression, e.g. a [`Modifier`] argument.
+    fn fold_expr(&mut self, expr: syn::Expr) -> syn::Expr {
+        expr
+    }
+
+    /// Folds a [`FunctionAttribute`], recursing into [`Modifier`]/[`Override`] sub-structure.
+    fn fold_function_attribute(&mut self, attr: FunctionAttribute) -> FunctionAttribute {
+        match attr {
+            FunctionAttribute::Modifier(modifier) => {
+                FunctionAttribute::Modifier(self.fold_modifier(modifier))
+            }
+            FunctionAttribute::Override(o) => FunctionAttribute::Override(self.fold_override(o)),
+            attr => attr,
+        }
+    }
+
+    /// Folds a [`FunctionAttributes`] list.
+    fn fold_function_attributes(&mut self, attrs: FunctionAttributes) -> FunctionAttributes {
+        FunctionAttributes(
+            attrs.0.into_iter().map(|attr| self.fold_function_attribute(attr)).collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct RenameIdents;
+
+    impl Fold for RenameIdents {
+        fn fold_ident(&mut self, _ident: SolIdent) -> SolIdent {
+            syn::parse_str("renamed").unwrap()
+        }
+    }
+
+    #[test]
+    fn fold_parameters_renames_every_ident() {
+        let params: crate::ParameterList = syn::parse_str("uint256 a, address b").unwrap();
+        let renamed = RenameIdents.fold_parameters(params);
+        assert!(renamed.names().all(|name| name.unwrap() == "renamed"));
+    }
+
+    struct StripVirtual;
+
+    impl Fold for StripVirtual {
+        fn fold_function_attributes(&mut self, attrs: FunctionAttributes) -> FunctionAttributes {
+            FunctionAttributes(attrs.0.into_iter().filter(|attr| !attr.is_virtual()).collect())
+        }
+    }
+
+    #[test]
+    fn fold_function_attributes_strips_virtual() {
+        let attrs: FunctionAttributes = syn::parse_str("virtual onlyOwner").unwrap();
+        let folded = StripVirtual.fold_function_attributes(attrs);
+        assert_eq!(folded.len(), 1);
+        assert!(!folded.has_virtual());
+        assert!(folded.modifier().is_some());
+    }
+
+    struct RenameModifierAndOverridePaths;
+
+    impl Fold for RenameModifierAndOverridePaths {
+        fn fold_sol_path(&mut self, _path: SolPath) -> SolPath {
+            syn::parse_str("Renamed").unwrap()
+        }
+    }
+
+    #[test]
+    fn fold_function_attributes_folds_modifier_and_override_paths() {
+        let attrs: FunctionAttributes = syn::parse_str("onlyOwner override(Base)").unwrap();
+        let folded = RenameModifierAndOverridePaths.fold_function_attributes(attrs);
+
+        let modifier = folded.modifier().unwrap();
+        assert_eq!(modifier.name.to_string(), "Renamed");
+
+        let over = folded.r#override().unwrap();
+        assert_eq!(over.paths.iter().next().unwrap().to_string(), "Renamed");
+    }
+}
@@ -1,6 +1,7 @@
 use super::VariableDeclaration;
 use crate::{SolIdent, Type};
 use std::{
+    collections::HashSet,
     fmt,
     ops::{Deref, DerefMut},
 };
@@ -121,6 +122,42 @@ impl<P> Parameters<P> {
         name
     }
 
+    /// Returns the full EIP-712 `encodeType` string for this type, named `name`: the primary
+    /// type's [`eip712_signature`](Self::eip712_signature), followed by the signature of every
+    /// struct transitively referenced by its fields, sorted lexicographically by name.
+    ///
+    /// `lookup` resolves a referenced type name to its field list, and is consulted recursively,
+    /// so it should return struct definitions rather than primitive types. Array element types
+    /// (`T[]`, `T[n]`) are unwrapped to their base type `T`, and tuple members are expanded
+    /// inline rather than treated as named structs, per the EIP-712 spec. A struct is never
+    /// visited twice, so self-referential and mutually-referential structs terminate correctly.
+    pub fn eip712_encode_type(&self, name: &str, lookup: impl Fn(&str) -> Option<&Self>) -> String {
+        let mut visited = HashSet::new();
+        visited.insert(name.to_string());
+        let mut referenced = Vec::new();
+        self.collect_eip712_refs(&lookup, &mut visited, &mut referenced);
+        referenced.sort_unstable();
+
+        let mut encoded = self.eip712_signature(name.to_string());
+        for referenced_name in &referenced {
+            if let Some(params) = lookup(referenced_name) {
+                encoded.push_str(&params.eip712_signature(referenced_name.clone()));
+            }
+        }
+        encoded
+    }
+
+    fn collect_eip712_refs(
+        &self,
+        lookup: &impl Fn(&str) -> Option<&Self>,
+        visited: &mut HashSet<String>,
+        referenced: &mut Vec<String>,
+    ) {
+        for ty in self.types() {
+            collect_eip712_type_refs(ty, lookup, visited, referenced);
+        }
+    }
+
     pub fn names(
         &self,
     ) -> impl ExactSizeIterator<Item = Option<&SolIdent>> + DoubleEndedIterator + Clone {
@@ -156,4 +193,74 @@ impl<P> Parameters<P> {
     pub fn visit_types_mut(&mut self, mut f: impl FnMut(&mut Type)) {
         self.types_mut().for_each(|ty| ty.visit_mut(&mut f))
     }
+
+    /// Returns `keccak256(self.eip712_encode_type(name, lookup))`, the EIP-712 `typeHash`.
+    #[cfg(feature = "eip712")]
+    pub fn eip712_type_hash(&self, name: &str, lookup: impl Fn(&str) -> Option<&Self>) -> [u8; 32] {
+        alloy_primitives::keccak256(self.eip712_encode_type(name, lookup)).0
+    }
+}
+
+/// Recursively collects the names of every user-defined struct referenced by `ty`, per the
+/// EIP-712 `encodeType` rules. Walking `ty`'s actual array/tuple/custom variants via
+/// [`Type::visit`] gets array-element unwrapping and inline tuple-member expansion for free,
+/// rather than re-deriving them from `ty`'s rendered `Display` form.
+fn collect_eip712_type_refs<P>(
+    ty: &Type,
+    lookup: &impl Fn(&str) -> Option<&Parameters<P>>,
+    visited: &mut HashSet<String>,
+    referenced: &mut Vec<String>,
+) {
+    ty.visit(&mut |ty| {
+        if let Type::Custom(path) = ty {
+            let name = path.to_string();
+            if visited.insert(name.clone()) {
+                if let Some(params) = lookup(&name) {
+                    referenced.push(name);
+                    params.collect_eip712_refs(lookup, visited, referenced);
+                }
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eip712_encode_type_terminates_on_self_reference() {
+        let node: ParameterList = syn::parse_str("Node[] children").unwrap();
+        let encoded = node.eip712_encode_type("Node", |name| (name == "Node").then_some(&node));
+        assert_eq!(encoded, "Node(Node[] children)");
+    }
+
+    #[test]
+    fn eip712_encode_type_appends_referenced_structs_sorted() {
+        let mail: ParameterList =
+            syn::parse_str("Person from,Person to,string contents").unwrap();
+        let person: ParameterList = syn::parse_str("string name,address wallet").unwrap();
+        let encoded = mail.eip712_encode_type("Mail", |name| match name {
+            "Person" => Some(&person),
+            _ => None,
+        });
+        assert_eq!(
+            encoded,
+            "Mail(Person from,Person to,string contents)Person(string name,address wallet)"
+        );
+    }
+
+    #[test]
+    fn eip712_encode_type_expands_tuple_members_inline() {
+        let container: ParameterList = syn::parse_str("(Person, uint256) data").unwrap();
+        let person: ParameterList = syn::parse_str("string name,address wallet").unwrap();
+        let encoded = container.eip712_encode_type("Container", |name| match name {
+            "Person" => Some(&person),
+            _ => None,
+        });
+        assert_eq!(
+            encoded,
+            "Container((Person,uint256) data)Person(string name,address wallet)"
+        );
+    }
 }
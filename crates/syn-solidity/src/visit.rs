@@ -0,0 +1,96 @@
+use crate::{
+    FunctionAttribute, FunctionAttributes, Modifier, Override, Parameters, SolIdent, SolPath,
+    Type, VariableDeclaration,
+};
+use syn::Expr;
+
+/// Syntax tree traversal to walk a shared borrow of a function signature.
+///
+/// Each method has a default implementation that recurses further into the tree by calling the
+/// `visit_*` methods of its children. A visitor that wants to inspect only a subset of nodes can
+/// override just those methods; overriding a method replaces its default recursion, so an
+/// override must call the children's `visit_*` methods itself to keep walking into that node's
+/// children.
+///
+/// This complements [`Parameters::visit_types`][crate::Parameters::visit_types], which only
+/// walks field types: `Visit` additionally covers parameter names, function attributes, modifier
+/// invocations (including their argument expressions), and override paths, so a linter or
+/// analyzer can inspect an entire parsed function signature without hand-rolling matches on
+/// [`FunctionAttribute`].
+pub trait Visit<'ast> {
+    fn visit_parameters<P>(&mut self, parameters: &'ast Parameters<P>) {
+        parameters.iter().for_each(|var| self.visit_variable_declaration(var));
+    }
+
+    fn visit_variable_declaration(&mut self, var: &'ast VariableDeclaration) {
+        if let Some(name) = &var.name {
+            self.visit_ident(name);
+        }
+        self.visit_type(&var.ty);
+    }
+
+    fn visit_ident(&mut self, _ident: &'ast SolIdent) {}
+
+    fn visit_type(&mut self, _ty: &'ast Type) {}
+
+    fn visit_function_attributes(&mut self, attrs: &'ast FunctionAttributes) {
+        attrs.iter().for_each(|attr| self.visit_function_attribute(attr));
+    }
+
+    fn visit_function_attribute(&mut self, attr: &'ast FunctionAttribute) {
+        if let FunctionAttribute::Modifier(modifier) = attr {
+            self.visit_modifier(modifier);
+        } else if let FunctionAttribute::Override(o) = attr {
+            self.visit_override(o);
+        }
+    }
+
+    fn visit_modifier(&mut self, modifier: &'ast Modifier) {
+        self.visit_sol_path(&modifier.name);
+        if let Some(arguments) = &modifier.arguments {
+            arguments.list.iter().for_each(|arg| self.visit_expr(arg));
+        }
+    }
+
+    fn visit_override(&mut self, o: &'ast Override) {
+        o.paths.iter().for_each(|path| self.visit_sol_path(path));
+    }
+
+    fn visit_sol_path(&mut self, _path: &'ast SolPath) {}
+
+    fn visit_expr(&mut self, _expr: &'ast Expr) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct CountModifiersAndOverrides {
+        modifiers: usize,
+        overrides: usize,
+    }
+
+    impl<'ast> Visit<'ast> for CountModifiersAndOverrides {
+        fn visit_modifier(&mut self, modifier: &'ast Modifier) {
+            self.modifiers += 1;
+            self.visit_sol_path(&modifier.name);
+        }
+
+        fn visit_override(&mut self, _o: &'ast Override) {
+            self.overrides += 1;
+        }
+    }
+
+    #[test]
+    fn visit_function_attributes_visits_every_modifier_and_override() {
+        let attrs: FunctionAttributes =
+            syn::parse_str("onlyOwner whenNotPaused override(Base)").unwrap();
+
+        let mut visitor = CountModifiersAndOverrides::default();
+        visitor.visit_function_attributes(&attrs);
+
+        assert_eq!(visitor.modifiers, 2);
+        assert_eq!(visitor.overrides, 1);
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{
+    FunctionAttribute, FunctionAttributes, Modifier, Override, Parameters, SolIdent, SolPath,
+    Type, VariableDeclaration,
+};
+use std::mem;
+use syn::Expr;
+
+/// Syntax tree traversal to mutate an exclusive borrow of a function signature.
+///
+/// This is the mutable counterpart of [`Visit`][crate::Visit] — same node set, same
+/// override-one-method-to-prune-the-rest default recursion — see its docs for the full
+/// rationale.
+pub trait VisitMut {
+    fn visit_parameters_mut<P>(&mut self, parameters: &mut Parameters<P>) {
+        parameters.iter_mut().for_each(|var| self.visit_variable_declaration_mut(var));
+    }
+
+    fn visit_variable_declaration_mut(&mut self, var: &mut VariableDeclaration) {
+        if let Some(name) = &mut var.name {
+            self.visit_ident_mut(name);
+        }
+        self.visit_type_mut(&mut var.ty);
+    }
+
+    fn visit_ident_mut(&mut self, _ident: &mut SolIdent) {}
+
+    fn visit_type_mut(&mut self, _ty: &mut Type) {}
+
+    fn visit_function_attributes_mut(&mut self, attrs: &mut FunctionAttributes) {
+        attrs.0 = mem::take(&mut attrs.0)
+            .into_iter()
+            .map(|mut attr| {
+                self.visit_function_attribute_mut(&mut attr);
+                attr
+            })
+            .collect();
+    }
+
+    fn visit_function_attribute_mut(&mut self, attr: &mut FunctionAttribute) {
+        if let FunctionAttribute::Modifier(modifier) = attr {
+            self.visit_modifier_mut(modifier);
+        } else if let FunctionAttribute::Override(o) = attr {
+            self.visit_override_mut(o);
+        }
+    }
+
+    fn visit_modifier_mut(&mut self, modifier: &mut Modifier) {
+        self.visit_sol_path_mut(&mut modifier.name);
+        if let Some(arguments) = &mut modifier.arguments {
+            arguments.list.iter_mut().for_each(|arg| self.visit_expr_mut(arg));
+        }
+    }
+
+    fn visit_override_mut(&mut self, o: &mut Override) {
+        o.paths.iter_mut().for_each(|path| self.visit_sol_path_mut(path));
+    }
+
+    fn visit_sol_path_mut(&mut self, _path: &mut SolPath) {}
+
+    fn visit_expr_mut(&mut self, _expr: &mut Expr) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct CountModifiers(usize);
+
+    impl VisitMut for CountModifiers {
+        fn visit_modifier_mut(&mut self, modifier: &mut Modifier) {
+            self.0 += 1;
+            self.visit_sol_path_mut(&mut modifier.name);
+        }
+    }
+
+    #[test]
+    fn visit_function_attributes_mut_preserves_all_attributes() {
+        let mut attrs: FunctionAttributes = syn::parse_str("virtual onlyOwner").unwrap();
+        assert_eq!(attrs.len(), 2);
+
+        let mut visitor = CountModifiers(0);
+        visitor.visit_function_attributes_mut(&mut attrs);
+
+        assert_eq!(attrs.len(), 2, "visiting must not drop any attribute");
+        assert!(attrs.has_virtual());
+        assert_eq!(visitor.0, 1);
+    }
+}